@@ -3,7 +3,10 @@
 
 use std::collections::hash_map::DefaultHasher;
 use std::hash::{Hash, Hasher};
-use std::time::SystemTime;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::Arc;
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, SystemTime};
 
 use atomic_cuckoo_filter::{CuckooFilter, CuckooFilterBuilder, CuckooFilterBuilderError};
 use derive_builder::Builder;
@@ -11,6 +14,50 @@ use serde::{Deserialize, Serialize};
 
 pub use atomic_cuckoo_filter::{Error, Lock, LockKind};
 
+/// A precomputed hash of an item, produced by [`ExpiringAtomicFilter::hash`].
+///
+/// Passing one of these to a `_hash` suffixed method (e.g.
+/// [`contains_hash`](ExpiringAtomicFilter::contains_hash)) lets the filter derive its
+/// per-slot fingerprint from the small, fixed-size hash instead of rehashing the original
+/// item on every slot.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ItemHash(u64);
+
+/// Error returned by [`ExpiringAtomicFilter::insert_with_ttl`] and
+/// [`ExpiringAtomicFilter::insert_unique_with_ttl`] when the requested per-item `ttl` is
+/// incompatible with the filter's configuration, or the underlying filter operation fails.
+#[derive(Debug)]
+pub enum TtlError {
+    /// `ttl` must be a positive multiple of `expiration_period`.
+    NotAMultipleOfExpirationPeriod,
+    /// `ttl` must not exceed the filter's configured `ttl`.
+    ExceedsFilterTtl,
+    /// The underlying cuckoo filter operation failed.
+    Filter(Error),
+}
+
+impl std::fmt::Display for TtlError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TtlError::NotAMultipleOfExpirationPeriod => {
+                write!(f, "ttl must be a positive multiple of expiration_period")
+            }
+            TtlError::ExceedsFilterTtl => {
+                write!(f, "ttl must not exceed the filter's configured ttl")
+            }
+            TtlError::Filter(err) => write!(f, "{err}"),
+        }
+    }
+}
+
+impl std::error::Error for TtlError {}
+
+impl From<Error> for TtlError {
+    fn from(value: Error) -> Self {
+        TtlError::Filter(value)
+    }
+}
+
 /// A serializable approximate membership query filter supporting lock-free concurrency and time-based expiration.
 #[derive(Debug, Builder, Serialize, Deserialize)]
 #[builder(build_fn(private, name = "base_build", validate = "Self::validate"))]
@@ -37,20 +84,27 @@ where
     #[builder(default = "500")]
     max_evictions: usize,
 
-    /// Number of seconds each item is expected to remain in the filter. Items may remain up to
+    /// Amount of time each item is expected to remain in the filter. Items may remain up to
     /// `ttl + expiration_period`, but no longer than that. Must be a multiple of `expiration_period`.
-    #[builder(default = "86400")]
-    pub ttl: u64,
+    #[builder(default = "Duration::from_secs(86400)")]
+    pub ttl: Duration,
 
-    /// Maximum number of seconds between expiration events.
-    #[builder(default = "3600")]
-    pub expiration_period: u64,
+    /// Maximum amount of time between expiration events.
+    #[builder(default = "Duration::from_secs(3600)")]
+    pub expiration_period: Duration,
 
-    /// Unix timestamp of when the filter was created.
+    /// Microsecond unix timestamp of when the filter was created.
     /// Needed to determine which slot in the buffer is selected for insert, lock, and expire operations.
     #[builder(setter(skip))]
     pub created: u64,
 
+    /// Microsecond unix timestamp of the last time the filter was reconciled against wall-clock
+    /// time, via [`reconcile`](Self::reconcile) or [`reconcile_now`](Self::reconcile_now).
+    /// Persisted across serialization so a filter restored after time offline can catch up on
+    /// the expirations it missed.
+    #[builder(setter(skip))]
+    pub last_expired: u64,
+
     /// A circular buffer of filters. At any given moment, one slot is being written to
     /// and one slot is waiting to be cleared by an externally-triggered expiration process.
     #[builder(setter(skip))]
@@ -69,8 +123,14 @@ where
     ///
     /// <https://docs.rs/atomic-cuckoo-filter/*/atomic_cuckoo_filter/struct.CuckooFilter.html#method.insert>
     pub fn insert<T: ?Sized + Hash>(&self, item: &T) -> Result<(), Error> {
+        self.insert_hash(self.hash(item))
+    }
+
+    /// Insert a precomputed [`ItemHash`] into the filter, without rehashing the original item.
+    /// See [`hash`](Self::hash).
+    pub fn insert_hash(&self, hash: ItemHash) -> Result<(), Error> {
         let write_slot = self.write_slot(Self::now_timestamp());
-        self.slots[write_slot].insert(item)
+        self.slots[write_slot].insert(&hash)
     }
 
     /// Check if an item is in the filter and insert it if is not present (atomically).
@@ -80,20 +140,73 @@ where
     ///
     /// <https://docs.rs/atomic-cuckoo-filter/*/atomic_cuckoo_filter/struct.CuckooFilter.html#method.insert_unique>
     pub fn insert_unique<T: ?Sized + Hash>(&self, item: &T) -> Result<bool, Error> {
-        self.insert_unique_as_of(item, Self::now_timestamp())
+        self.insert_unique_as_of(self.hash(item), Self::now_timestamp())
     }
 
     #[inline(always)]
-    fn insert_unique_as_of<T: ?Sized + Hash>(&self, item: &T, now: u64) -> Result<bool, Error> {
+    fn insert_unique_as_of(&self, hash: ItemHash, now: u64) -> Result<bool, Error> {
         let write_slot = self.write_slot(now);
-        self.slots[write_slot].insert_unique(item)
+        self.slots[write_slot].insert_unique(&hash)
+    }
+
+    /// Insert an item with an explicit `ttl`, no longer than the filter's configured [`ttl`](Self::ttl).
+    ///
+    /// `ttl` must be a positive multiple of `expiration_period` and no larger than the filter's
+    /// configured `ttl`, or [`TtlError::NotAMultipleOfExpirationPeriod`] /
+    /// [`TtlError::ExceedsFilterTtl`] is returned.
+    pub fn insert_with_ttl<T: ?Sized + Hash>(&self, item: &T, ttl: Duration) -> Result<(), TtlError> {
+        let slot = self.ttl_write_slot(Self::now_timestamp(), ttl)?;
+        self.slots[slot].insert(&self.hash(item))?;
+        Ok(())
+    }
+
+    /// Check if an item is in the filter and insert it with an explicit `ttl` if it is not
+    /// present (atomically), no longer than the filter's configured [`ttl`](Self::ttl).
+    ///
+    /// `ttl` must be a positive multiple of `expiration_period` and no larger than the filter's
+    /// configured `ttl`, or [`TtlError::NotAMultipleOfExpirationPeriod`] /
+    /// [`TtlError::ExceedsFilterTtl`] is returned.
+    pub fn insert_unique_with_ttl<T: ?Sized + Hash>(
+        &self,
+        item: &T,
+        ttl: Duration,
+    ) -> Result<bool, TtlError> {
+        let slot = self.ttl_write_slot(Self::now_timestamp(), ttl)?;
+        Ok(self.slots[slot].insert_unique(&self.hash(item))?)
+    }
+
+    /// Returns the slot that an item with the given per-item `ttl` should be written to so that
+    /// it expires after `ttl / expiration_period` periods rather than living for the full buffer.
+    #[inline(always)]
+    fn ttl_write_slot(&self, now: u64, ttl: Duration) -> Result<usize, TtlError> {
+        let ttl_micros = ttl.as_micros() as u64;
+        let expiration_period_micros = self.expiration_period_micros();
+
+        if ttl_micros == 0 || !ttl_micros.is_multiple_of(expiration_period_micros) {
+            return Err(TtlError::NotAMultipleOfExpirationPeriod);
+        }
+        if ttl_micros > self.ttl_micros() {
+            return Err(TtlError::ExceedsFilterTtl);
+        }
+
+        let periods = ttl_micros / expiration_period_micros;
+        let slot_count = self.slots.len() as u64;
+        let write_slot = self.write_slot(now) as u64;
+
+        Ok(((write_slot + periods + 2) % slot_count) as usize)
     }
 
     /// Counts the number of occurrences of an item in the filter.
     ///
     /// <https://docs.rs/atomic-cuckoo-filter/*/atomic_cuckoo_filter/struct.CuckooFilter.html#method.count>
     pub fn count<T: ?Sized + Hash>(&self, item: &T) -> usize {
-        self.slots.iter().map(|f| f.count(item)).sum()
+        self.count_hash(self.hash(item))
+    }
+
+    /// Count the occurrences of a precomputed [`ItemHash`] in the filter, without rehashing the
+    /// original item once per slot. See [`hash`](Self::hash).
+    pub fn count_hash(&self, hash: ItemHash) -> usize {
+        self.slots.iter().map(|f| f.count(&hash)).sum()
     }
 
     /// Attempts to remove an item from the filter.
@@ -102,11 +215,17 @@ where
     ///
     /// <https://docs.rs/atomic-cuckoo-filter/*/atomic_cuckoo_filter/struct.CuckooFilter.html#method.remove>
     pub fn remove<T: ?Sized + Hash>(&self, item: &T) -> bool {
+        self.remove_hash(self.hash(item))
+    }
+
+    /// Attempts to remove a precomputed [`ItemHash`] from the filter, without rehashing the
+    /// original item once per slot. See [`hash`](Self::hash).
+    pub fn remove_hash(&self, hash: ItemHash) -> bool {
         for filter in &self.slots {
             // Removing a non-existent item can corrupt the filter. Although `contains`
             // can produce false positives, this risk mitigated by configuring
             // the default fingerprint size as 32.
-            if filter.contains(item) && filter.remove(item) {
+            if filter.contains(&hash) && filter.remove(&hash) {
                 return true;
             }
         }
@@ -120,14 +239,71 @@ where
     ///
     /// <https://docs.rs/atomic-cuckoo-filter/*/atomic_cuckoo_filter/struct.CuckooFilter.html#method.contains>
     pub fn contains<T: ?Sized + Hash>(&self, item: &T) -> bool {
+        self.contains_hash(self.hash(item))
+    }
+
+    /// Check if a precomputed [`ItemHash`] is in the filter, without rehashing the original item
+    /// once per slot. See [`hash`](Self::hash).
+    pub fn contains_hash(&self, hash: ItemHash) -> bool {
         for filter in &self.slots {
-            if filter.contains(item) {
+            if filter.contains(&hash) {
                 return true;
             }
         }
         false
     }
 
+    /// Compute a stable hash for `item` using the filter's configured hasher, for reuse across
+    /// multiple `_hash` suffixed calls (e.g. [`contains_hash`](Self::contains_hash) followed by
+    /// [`insert_hash`](Self::insert_hash)) so the item is hashed once rather than once per slot.
+    pub fn hash<T: ?Sized + Hash>(&self, item: &T) -> ItemHash {
+        let mut hasher = H::default();
+        item.hash(&mut hasher);
+        ItemHash(hasher.finish())
+    }
+
+    /// Check if an item was inserted within the last `max_age`, without waiting for it to age
+    /// out of the whole filter. Because slots are ordered by insertion time, only the slots
+    /// written within `max_age` need to be inspected, rather than every slot like
+    /// [`contains`](Self::contains).
+    ///
+    /// Returns true if the item is possibly present within `max_age` (may have false positives),
+    /// false if it is definitely not.
+    pub fn contains_within<T: ?Sized + Hash>(&self, item: &T, max_age: Duration) -> bool {
+        let hash = self.hash(item);
+        self.slots_within(max_age)
+            .into_iter()
+            .any(|slot| self.slots[slot].contains(&hash))
+    }
+
+    /// Counts the occurrences of an item inserted within the last `max_age`. See
+    /// [`contains_within`](Self::contains_within).
+    pub fn count_within<T: ?Sized + Hash>(&self, item: &T, max_age: Duration) -> usize {
+        let hash = self.hash(item);
+        self.slots_within(max_age)
+            .into_iter()
+            .map(|slot| self.slots[slot].count(&hash))
+            .sum()
+    }
+
+    /// Returns the indices of the slots written within the last `max_age`, walking backward
+    /// from the current write slot and skipping the slot currently awaiting expiration.
+    fn slots_within(&self, max_age: Duration) -> Vec<usize> {
+        let slot_count = self.slots.len() as u64;
+        let write_slot = self.write_slot(Self::now_timestamp()) as u64;
+        let expire_slot = (1 + write_slot) % slot_count;
+
+        let periods = (max_age.as_micros() as u64)
+            .div_ceil(self.expiration_period_micros())
+            .min(slot_count);
+
+        (0..periods)
+            .map(|i| (write_slot + slot_count - i) % slot_count)
+            .filter(|&slot| slot != expire_slot)
+            .map(|slot| slot as usize)
+            .collect()
+    }
+
     /// Get the number of elements in the filter.
     pub fn len(&self) -> usize {
         self.slots.iter().map(|f| f.len()).sum()
@@ -181,10 +357,43 @@ where
         item_count
     }
 
+    /// Catch up on expirations that were missed while the filter was not running, e.g. between
+    /// being serialized and later deserialized. Clears every slot made stale by the time elapsed
+    /// since the filter was last reconciled, advancing forward from the expire position as of the
+    /// last reconciliation, clamped to clearing every slot once the gap reaches the full buffer
+    /// duration.
+    ///
+    /// Returns the number of items that were removed.
+    pub fn reconcile_now(&mut self) -> usize {
+        self.reconcile(Self::now_timestamp())
+    }
+
+    /// Returns the number of items that were removed.
+    pub fn reconcile(&mut self, now: u64) -> usize {
+        let slot_count = self.slots.len() as u64;
+        let elapsed = now.saturating_sub(self.last_expired);
+        let periods = (elapsed / self.expiration_period_micros()).min(slot_count);
+
+        // the slots missed since `last_expired` follow the expire slot of that time
+        let expire_slot = (1 + self.write_slot(self.last_expired)) as u64 % slot_count;
+        let mut removed = 0;
+        for i in 1..=periods {
+            let filter = &self.slots[((expire_slot + i) % slot_count) as usize];
+            let item_count = filter.len();
+            if item_count > 0 {
+                filter.clear();
+            }
+            removed += item_count;
+        }
+
+        self.last_expired = now;
+        removed
+    }
+
     #[inline(always)]
     fn write_slot(&self, now: u64) -> usize {
         let slot_count = self.slots.len() as u64;
-        let ttl_segment_duration = self.ttl / (slot_count - 2);
+        let ttl_segment_duration = self.ttl_micros() / (slot_count - 2);
         let buffer_duration = ttl_segment_duration * slot_count;
         let now_buffer_time = (now - self.created) % buffer_duration;
 
@@ -196,12 +405,127 @@ where
         (write_slot_start / ttl_segment_duration) as usize
     }
 
+    /// `ttl`, in microseconds.
+    #[inline(always)]
+    fn ttl_micros(&self) -> u64 {
+        self.ttl.as_micros() as u64
+    }
+
+    /// `expiration_period`, in microseconds.
+    #[inline(always)]
+    fn expiration_period_micros(&self) -> u64 {
+        self.expiration_period.as_micros() as u64
+    }
+
     #[inline(always)]
     fn now_timestamp() -> u64 {
         SystemTime::now()
             .duration_since(SystemTime::UNIX_EPOCH)
             .expect("epoch should be earlier than now")
-            .as_secs()
+            .as_micros() as u64
+    }
+}
+
+impl<H> ExpiringAtomicFilter<H>
+where
+    H: Hasher + Default + Send + Sync + 'static,
+{
+    /// Spawn a background thread that calls [`expire`](Self::expire) once per
+    /// `expiration_period`, so expiration keeps happening without a caller having to remember to
+    /// invoke `expire`/`expire_as_of` themselves. Because inserts and lookups are already
+    /// lock-free, the driver only needs shared access and never blocks readers.
+    ///
+    /// Returns a handle whose `Drop` (or explicit [`stop`](ExpirerHandle::stop)) cleanly joins
+    /// the background thread.
+    pub fn spawn_expirer(self: Arc<Self>) -> ExpirerHandle {
+        let stop = Arc::new(AtomicBool::new(false));
+        let paused = Arc::new(AtomicBool::new(false));
+        let evicted = Arc::new(AtomicU64::new(0));
+        let period = Duration::from_micros(self.expiration_period_micros());
+
+        let thread_stop = Arc::clone(&stop);
+        let thread_paused = Arc::clone(&paused);
+        let thread_evicted = Arc::clone(&evicted);
+
+        let thread = thread::spawn(move || {
+            // Sleep in short increments rather than for the whole `period` at once, so that
+            // stopping the thread doesn't have to wait for a potentially long
+            // `expiration_period` (e.g. the default hour) to elapse.
+            let tick = period.min(Duration::from_millis(50));
+            let mut elapsed = Duration::ZERO;
+
+            while !thread_stop.load(Ordering::Acquire) {
+                thread::sleep(tick);
+                elapsed += tick;
+                if elapsed < period {
+                    continue;
+                }
+                elapsed = Duration::ZERO;
+
+                if thread_stop.load(Ordering::Acquire) {
+                    break;
+                }
+                if thread_paused.load(Ordering::Acquire) {
+                    continue;
+                }
+                let removed = self.expire();
+                thread_evicted.fetch_add(removed as u64, Ordering::Relaxed);
+            }
+        });
+
+        ExpirerHandle {
+            stop,
+            paused,
+            evicted,
+            thread: Some(thread),
+        }
+    }
+}
+
+/// Handle to the background thread spawned by [`ExpiringAtomicFilter::spawn_expirer`].
+///
+/// Dropping the handle stops the thread and joins it; use [`stop`](Self::stop) to do so
+/// explicitly and [`pause`](Self::pause)/[`resume`](Self::resume) to temporarily suspend
+/// expiration without tearing the thread down.
+pub struct ExpirerHandle {
+    stop: Arc<AtomicBool>,
+    paused: Arc<AtomicBool>,
+    evicted: Arc<AtomicU64>,
+    thread: Option<JoinHandle<()>>,
+}
+
+impl ExpirerHandle {
+    /// Temporarily suspend expiration without stopping the background thread.
+    pub fn pause(&self) {
+        self.paused.store(true, Ordering::Release);
+    }
+
+    /// Resume expiration after a [`pause`](Self::pause).
+    pub fn resume(&self) {
+        self.paused.store(false, Ordering::Release);
+    }
+
+    /// Total number of items evicted by the background thread so far.
+    pub fn evicted(&self) -> u64 {
+        self.evicted.load(Ordering::Relaxed)
+    }
+
+    /// Stop the background thread and wait for it to exit.
+    pub fn stop(mut self) {
+        self.stop_and_join();
+    }
+
+    fn stop_and_join(&mut self) {
+        self.stop.store(true, Ordering::Release);
+        if let Some(thread) = self.thread.take() {
+            let _ = thread.join();
+        }
+    }
+}
+
+impl Drop for ExpirerHandle {
+    fn drop(&mut self) {
+        self.stop_and_join();
     }
 }
 
@@ -235,7 +559,7 @@ where
 {
     fn validate(&self) -> Result<(), String> {
         if let (Some(ttl), Some(expiration_period)) = (self.ttl, self.expiration_period)
-            && !ttl.is_multiple_of(expiration_period)
+            && !ttl.as_micros().is_multiple_of(expiration_period.as_micros())
         {
             return Err("ttl must be a multiple of expiration_period".into());
         }
@@ -247,9 +571,10 @@ where
         let mut filter = self.base_build()?;
 
         filter.created = ExpiringAtomicFilter::<H>::now_timestamp();
+        filter.last_expired = filter.created;
 
         // Reserve two additional slots for partially expired and fully expired items.
-        let slot_count = 2 + (filter.ttl / filter.expiration_period) as usize;
+        let slot_count = 2 + (filter.ttl_micros() / filter.expiration_period_micros()) as usize;
         let unexpired_slot_capacity = filter.capacity / (slot_count - 2);
 
         let mut slots = Vec::with_capacity(slot_count);
@@ -294,21 +619,22 @@ mod tests {
     fn test_write_slot() {
         let now = ExpiringAtomicFilter::<DefaultHasher>::now_timestamp();
 
-        let filter = ExpiringAtomicFilter::builder()
+        let mut filter = ExpiringAtomicFilter::builder()
             .capacity(2)
-            .ttl(hs(25))
-            .expiration_period(hs(12) + ms(30))
+            .ttl(Duration::from_micros(hs(25)))
+            .expiration_period(Duration::from_micros(hs(12) + ms(30)))
             .build()
             .unwrap();
+        filter.created = now;
 
         let cases = [
-            (now + hs(12) + ms(29) + 59, 0),
+            (now + hs(12) + ms(29) + secs(59), 0),
             (now + hs(12) + ms(30), 1),
-            (now + hs(24) + ms(59) + 59, 1),
+            (now + hs(24) + ms(59) + secs(59), 1),
             (now + hs(25), 2),
-            (now + hs(37) + ms(29) + 59, 2),
+            (now + hs(37) + ms(29) + secs(59), 2),
             (now + hs(37) + ms(30), 3),
-            (now + hs(49) + ms(59) + 59, 3),
+            (now + hs(49) + ms(59) + secs(59), 3),
             (now + hs(50), 0),
         ];
 
@@ -321,20 +647,21 @@ mod tests {
     fn test_expire_as_of() {
         let now = ExpiringAtomicFilter::<DefaultHasher>::now_timestamp();
 
-        let filter = ExpiringAtomicFilterBuilder::<ahash::AHasher>::default()
+        let mut filter = ExpiringAtomicFilterBuilder::<ahash::AHasher>::default()
             .capacity(50)
-            .ttl(hs(25))
-            .expiration_period(ms(30))
+            .ttl(Duration::from_micros(hs(25)))
+            .expiration_period(Duration::from_micros(ms(30)))
             .build()
             .unwrap();
+        filter.created = now;
 
         // do not expire an item until the TTL has elapsed
         assert_eq!(
-            filter.insert_unique_as_of("item1", now + ms(29) + 59),
+            filter.insert_unique_as_of(filter.hash("item1"), now + ms(29) + secs(59)),
             Ok(true)
         );
         assert_eq!(
-            filter.expire_as_of(now + hs(25) + ms(29) + 59),
+            filter.expire_as_of(now + hs(25) + ms(29) + secs(59)),
             0,
             "item1 at max age"
         );
@@ -348,7 +675,7 @@ mod tests {
 
         // do not expire an item older than TTL + expiration period
         assert_eq!(
-            filter.insert_unique_as_of("item2", now + hs(24) + ms(59) + 59),
+            filter.insert_unique_as_of(filter.hash("item2"), now + hs(24) + ms(59) + secs(59)),
             Ok(true)
         );
         assert_eq!(
@@ -358,18 +685,249 @@ mod tests {
         );
         assert!(filter.contains("item2"));
         assert_eq!(
-            filter.expire_as_of(now + hs(50) + ms(29) + 59),
+            filter.expire_as_of(now + hs(50) + ms(29) + secs(59)),
             1,
             "item2 expired at last possible time"
         );
         assert!(!filter.contains("item2"));
     }
 
+    #[test]
+    fn test_hash_consistency_across_paths() {
+        let filter = ExpiringAtomicFilterBuilder::<ahash::AHasher>::default()
+            .capacity(1000)
+            .ttl(Duration::from_micros(hs(1)))
+            .expiration_period(Duration::from_micros(ms(30)))
+            .build()
+            .unwrap();
+
+        // inserted via insert_unique, looked up via contains/contains_hash/count
+        assert_eq!(filter.insert_unique("item1"), Ok(true));
+        assert!(filter.contains("item1"));
+        assert!(filter.contains_hash(filter.hash("item1")));
+        assert_eq!(filter.count("item1"), 1);
+
+        // inserted via insert_with_ttl, looked up via contains/contains_within
+        filter
+            .insert_with_ttl("item2", Duration::from_micros(ms(30)))
+            .unwrap();
+        assert!(filter.contains("item2"));
+        assert!(filter.contains_within("item2", Duration::from_micros(ms(30))));
+
+        // inserted via insert_unique_with_ttl, looked up via count_within/remove
+        assert!(filter
+            .insert_unique_with_ttl("item3", Duration::from_micros(ms(30)))
+            .unwrap());
+        assert_eq!(filter.count_within("item3", Duration::from_micros(ms(30))), 1);
+        assert!(filter.remove("item3"));
+        assert!(!filter.contains("item3"));
+    }
+
+    #[test]
+    fn test_insert_with_ttl_survival() {
+        let now = ExpiringAtomicFilter::<DefaultHasher>::now_timestamp();
+
+        let mut filter = ExpiringAtomicFilterBuilder::<ahash::AHasher>::default()
+            .capacity(50)
+            .ttl(Duration::from_micros(hs(1)))
+            .expiration_period(Duration::from_micros(ms(30)))
+            .build()
+            .unwrap();
+        filter.created = now;
+
+        filter.insert("plain").unwrap();
+        filter
+            .insert_with_ttl("full", Duration::from_micros(hs(1)))
+            .unwrap();
+        filter
+            .insert_with_ttl("short", Duration::from_micros(ms(30)))
+            .unwrap();
+
+        // the short-ttl item expires one grace period after its own ttl, well before the
+        // plain/full-ttl items do
+        assert_eq!(
+            filter.expire_as_of(now + ms(59) + secs(59)),
+            0,
+            "short ttl item not yet due"
+        );
+        assert!(filter.contains("short"));
+        assert_eq!(
+            filter.expire_as_of(now + ms(60)),
+            1,
+            "short ttl item expires after its ttl plus one grace period"
+        );
+        assert!(!filter.contains("short"));
+        assert!(filter.contains("plain"));
+        assert!(filter.contains("full"));
+
+        // insert_with_ttl(item, filter.ttl) survives exactly as long as a plain insert
+        assert_eq!(
+            filter.expire_as_of(now + hs(1) + ms(29) + secs(59)),
+            0,
+            "full ttl item and plain insert not yet due"
+        );
+        assert_eq!(
+            filter.expire_as_of(now + hs(1) + ms(30)),
+            2,
+            "full ttl item expires together with the plain insert"
+        );
+        assert!(!filter.contains("plain"));
+        assert!(!filter.contains("full"));
+    }
+
+    #[test]
+    fn test_spawn_expirer() {
+        use std::time::Instant;
+
+        let filter = Arc::new(
+            ExpiringAtomicFilterBuilder::<ahash::AHasher>::default()
+                .capacity(50)
+                .ttl(Duration::from_millis(20))
+                .expiration_period(Duration::from_millis(10))
+                .build()
+                .unwrap(),
+        );
+        filter.insert("item").unwrap();
+
+        let handle = Arc::clone(&filter).spawn_expirer();
+        std::thread::sleep(Duration::from_millis(200));
+        assert!(handle.evicted() > 0, "background thread should have evicted the item");
+        assert!(!filter.contains("item"));
+
+        // stopping must not block for the full expiration_period, regardless of how large it is
+        let start = Instant::now();
+        handle.stop();
+        assert!(
+            start.elapsed() < Duration::from_millis(100),
+            "stop() should return promptly"
+        );
+    }
+
+    #[test]
+    fn test_sub_second_expiration_period() {
+        let now = ExpiringAtomicFilter::<DefaultHasher>::now_timestamp();
+
+        let mut filter = ExpiringAtomicFilterBuilder::<ahash::AHasher>::default()
+            .capacity(50)
+            .ttl(Duration::from_millis(5000))
+            .expiration_period(Duration::from_millis(500))
+            .build()
+            .unwrap();
+        filter.created = now;
+
+        let ttl = 5_000_000;
+        let expiration_period = 500_000;
+
+        assert_eq!(
+            filter.insert_unique_as_of(filter.hash("item"), now + expiration_period - 1_000),
+            Ok(true)
+        );
+
+        // do not expire an item until the TTL has elapsed, at a sub-second expiration_period
+        assert_eq!(
+            filter.expire_as_of(now + ttl + expiration_period - 1_000),
+            0,
+            "item at max age"
+        );
+        assert!(filter.contains("item"));
+        assert_eq!(
+            filter.expire_as_of(now + ttl + expiration_period),
+            1,
+            "item expires after TTL"
+        );
+        assert!(!filter.contains("item"));
+    }
+
+    #[test]
+    fn test_reconcile() {
+        let now = ExpiringAtomicFilter::<DefaultHasher>::now_timestamp();
+
+        let mut filter = ExpiringAtomicFilterBuilder::<ahash::AHasher>::default()
+            .capacity(50)
+            .ttl(Duration::from_micros(hs(3)))
+            .expiration_period(Duration::from_micros(ms(30)))
+            .build()
+            .unwrap();
+        // slot_count = 2 + hs(3) / ms(30) = 8
+        filter.created = now;
+        filter.last_expired = now;
+
+        assert_eq!(
+            filter.insert_unique_as_of(filter.hash("item1"), now),
+            Ok(true)
+        );
+
+        // no reconcile is needed before a full expiration_period has elapsed
+        assert_eq!(filter.reconcile(now + ms(29) + secs(59)), 0);
+        assert!(filter.contains("item1"));
+
+        // catching up across several missed periods clears each of them in turn, but not
+        // item1's own slot until its ttl plus one grace period has actually elapsed
+        assert_eq!(filter.reconcile(now + ms(30) * 3), 0);
+        assert!(filter.contains("item1"), "item1's own slot isn't due yet");
+
+        assert_eq!(
+            filter.reconcile(now + hs(3) + ms(30)),
+            1,
+            "item1's slot is now stale"
+        );
+        assert!(!filter.contains("item1"));
+        assert_eq!(filter.last_expired, now + hs(3) + ms(30));
+
+        // a gap spanning more than the whole buffer only clears each slot once
+        filter
+            .insert_with_ttl("item2", Duration::from_micros(hs(3)))
+            .unwrap();
+        assert_eq!(filter.reconcile(now + hs(24)), 1);
+        assert!(!filter.contains("item2"));
+    }
+
+    #[test]
+    fn test_contains_within() {
+        let mut filter = ExpiringAtomicFilterBuilder::<ahash::AHasher>::default()
+            .capacity(1000)
+            .ttl(Duration::from_micros(hs(3)))
+            .expiration_period(Duration::from_micros(ms(30)))
+            .build()
+            .unwrap();
+        // slot_count = 2 + hs(3) / ms(30) = 8
+
+        filter.insert_unique("recent").unwrap();
+
+        // simulate an item written several periods ago by backdating `created`, so
+        // now_timestamp() lands several slots ahead of where "old" was actually written
+        let original_created = filter.created;
+        filter.created = original_created - ms(30) * 4;
+        filter.insert_unique("old").unwrap();
+        filter.created = original_created;
+
+        assert!(filter.contains("recent"));
+        assert!(filter.contains("old"));
+
+        // "old" was written ~4 periods ago, so it falls outside a 1-period window...
+        assert!(!filter.contains_within("old", Duration::from_micros(ms(30))));
+        assert_eq!(filter.count_within("old", Duration::from_micros(ms(30))), 0);
+        // ...but "recent" was written just now, so it's within any window
+        assert!(filter.contains_within("recent", Duration::from_micros(ms(30))));
+        assert_eq!(filter.count_within("recent", Duration::from_micros(ms(30))), 1);
+
+        // a window wide enough to cover both finds them
+        assert!(filter.contains_within("old", Duration::from_micros(ms(30) * 5)));
+        assert_eq!(filter.count_within("old", Duration::from_micros(ms(30) * 5)), 1);
+    }
+
+    /// Microseconds in `i` hours.
     fn hs(i: u64) -> u64 {
-        i * 3600
+        i * 3_600_000_000
     }
 
+    /// Microseconds in `i` minutes.
     fn ms(i: u64) -> u64 {
-        i * 60
+        i * 60_000_000
+    }
+
+    /// Microseconds in `i` seconds.
+    fn secs(i: u64) -> u64 {
+        i * 1_000_000
     }
 }